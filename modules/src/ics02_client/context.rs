@@ -0,0 +1,42 @@
+//! Defines the host-chain self-consistency context used to validate that a newly submitted
+//! consensus state actually matches what this chain recorded for itself at that height.
+//!
+//! `ChainReader`/`ChainKeeper` are deliberately a separate read/write pair from
+//! `ClientReader`/`ClientKeeper`: only `create_client` needs to look at the host chain's own
+//! history (and only when the client being created targets the host chain itself), so no
+//! other `ClientReader` implementor should be forced to supply it.
+
+use crate::ics23_commitment::CommitmentRoot;
+use crate::Height;
+
+/// A header of the host chain, as it would be seen by a light client tracking this chain.
+pub trait SelfHeader: Clone + core::fmt::Debug + Send + Sync {
+    fn height(&self) -> Height;
+    fn commitment_root(&self) -> CommitmentRoot;
+    fn timestamp(&self) -> u64;
+}
+
+/// The subset of the host chain's own history that a client creation request can be checked
+/// against, keyed by the height at which it was recorded.
+#[derive(Clone, Debug, PartialEq)]
+pub struct HistoricalInfo<H: SelfHeader> {
+    pub header: H,
+}
+
+/// Read-only access to the host chain's own recorded history, so that `create_client` can
+/// confirm a client being created for this chain is consistent with what actually happened.
+pub trait ChainReader {
+    type SelfHeader: SelfHeader;
+
+    /// Returns the historical info this chain recorded for itself at `height`, if still
+    /// within the retained window.
+    fn self_historical_info(&self, height: Height) -> Option<HistoricalInfo<Self::SelfHeader>>;
+}
+
+/// Write-side counterpart of [`ChainReader`], used by the host chain to record its own
+/// headers as they are produced so that later `create_client` calls can be checked.
+pub trait ChainKeeper {
+    type SelfHeader: SelfHeader;
+
+    fn store_historical_info(&mut self, height: Height, info: HistoricalInfo<Self::SelfHeader>);
+}
@@ -0,0 +1,55 @@
+//! ICS02 client error type, built on `flex-error` rather than `thiserror` so that callers
+//! embedding these handlers (e.g. inside a CosmWasm or on-chain light client) can plug in
+//! their own tracing/reporting backend instead of inheriting `std::error::Error`.
+
+#[cfg(not(feature = "std"))]
+use alloc::string::String;
+#[cfg(feature = "std")]
+use std::string::String;
+
+use flex_error::define_error;
+
+use crate::ics24_host::identifier::ClientId;
+use crate::Height;
+
+define_error! {
+    Error {
+        ClientAlreadyExists
+            { client_id: ClientId }
+            | e | { format_args!("a client with id {0} already exists", e.client_id) },
+
+        ClientNotFound
+            { client_id: ClientId }
+            | e | { format_args!("client with id {0} not found", e.client_id) },
+
+        FrozenClient
+            { client_id: ClientId }
+            | e | { format_args!("client with id {0} is frozen and cannot be updated", e.client_id) },
+
+        ConsensusStateNotFound
+            { client_id: ClientId, height: Height }
+            | e | {
+                format_args!(
+                    "consensus state for client {0} at height {1} not found",
+                    e.client_id, e.height
+                )
+            },
+
+        InvalidConsensusState
+            { client_id: ClientId }
+            | e | {
+                format_args!(
+                    "consensus state submitted for client {0} does not match the host chain's own history",
+                    e.client_id
+                )
+            },
+
+        HeaderVerificationFailure
+            { reason: String }
+            | e | { format_args!("header failed to verify against the client's trusted state: {0}", e.reason) },
+
+        MisbehaviourVerificationFailure
+            { reason: String }
+            | e | { format_args!("submitted headers do not constitute valid misbehaviour evidence: {0}", e.reason) },
+    }
+}
@@ -1,14 +1,16 @@
 #![allow(unreachable_code, unused_variables)]
 
+use core::time::Duration;
+
 use crate::handler::{HandlerOutput, HandlerResult};
 use crate::ics02_client::client_def::{AnyClient, ClientDef};
 use crate::ics02_client::client_type::ClientType;
-use crate::ics02_client::error::{Error, Kind};
+use crate::ics02_client::context::{ChainReader, SelfHeader};
+use crate::ics02_client::error::Error;
 use crate::ics02_client::handler::{ClientEvent, ClientKeeper, ClientReader};
 use crate::ics02_client::msgs::MsgCreateAnyClient;
 use crate::ics02_client::state::{ClientState, ConsensusState};
 use crate::ics24_host::identifier::ClientId;
-use std::time::Duration;
 
 #[derive(Debug)]
 pub struct CreateClientResult<CD: ClientDef> {
@@ -18,8 +20,14 @@ pub struct CreateClientResult<CD: ClientDef> {
     consensus_state: CD::ConsensusState,
 }
 
-pub fn process(
+/// The client type used for clients that track this chain itself, as opposed to a
+/// counterparty. Only a client of this type is subject to the host chain self-consistency
+/// check in [`process`]; a foreign client merely colliding on height should not be.
+const HOST_CLIENT_TYPE: ClientType = ClientType::Mock;
+
+pub fn process<H: SelfHeader>(
     ctx: &dyn ClientReader,
+    chain_ctx: &dyn ChainReader<SelfHeader = H>,
     msg: MsgCreateAnyClient<AnyClient>,
 ) -> HandlerResult<CreateClientResult<AnyClient>, Error> {
     let mut output = HandlerOutput::builder();
@@ -32,17 +40,34 @@ pub fn process(
     } = msg;
 
     if ctx.client_state(&client_id).is_some() {
-        return Err(Kind::ClientAlreadyExists(client_id).into());
+        return Err(Error::client_already_exists(client_id));
     }
 
     output.log("success: no client state found");
 
     if ctx.client_type(&client_id).is_some() {
-        return Err(Kind::ClientAlreadyExists(client_id).into());
+        return Err(Error::client_already_exists(client_id));
     }
 
     output.log("success: no client type found");
 
+    // Only a client tracking this chain itself can be checked against this chain's own
+    // history; a client tracking a foreign chain whose consensus height happens to collide
+    // with one of ours must not be rejected over an unrelated mismatch.
+    if client_type == HOST_CLIENT_TYPE {
+        if let Some(historical_info) = chain_ctx.self_historical_info(consensus_state.height()) {
+            let self_header = historical_info.header;
+
+            if consensus_state.root() != &self_header.commitment_root()
+                || consensus_state.timestamp() != self_header.timestamp()
+            {
+                return Err(Error::invalid_consensus_state(client_id));
+            }
+
+            output.log("success: submitted consensus state matches host chain history");
+        }
+    }
+
     output.emit(ClientEvent::ClientCreated(client_id.clone()));
 
     Ok(output.with_result(CreateClientResult {
@@ -67,6 +92,8 @@ pub fn keep(
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::ics02_client::context::HistoricalInfo;
+    use crate::ics02_client::error::ErrorDetail;
     use crate::ics02_client::header::Header;
     use crate::ics02_client::mocks::*;
     use crate::ics02_client::state::{ClientState, ConsensusState};
@@ -76,7 +103,6 @@ mod tests {
     use crate::ics23_commitment::CommitmentRoot;
     use crate::Height;
     use std::str::FromStr;
-    use thiserror::Error;
 
     #[test]
     fn test_create_client_ok() {
@@ -87,6 +113,7 @@ mod tests {
             client_type: None,
             client_state: None,
             consensus_state: None,
+            historical_info: None,
         };
 
         let msg = MsgCreateAnyClient {
@@ -96,7 +123,7 @@ mod tests {
             consensus_state: MockConsensusState(42).into(),
         };
 
-        let output = process(&reader, msg.clone());
+        let output = process(&reader, &reader, msg.clone());
 
         match output {
             Ok(HandlerOutput {
@@ -132,6 +159,7 @@ mod tests {
             client_type: Some(ClientType::Tendermint),
             client_state: None,
             consensus_state: None,
+            historical_info: None,
         };
 
         let msg = MsgCreateAnyClient {
@@ -141,10 +169,13 @@ mod tests {
             consensus_state: MockConsensusState(42).into(),
         };
 
-        let output = process(&reader, msg.clone());
+        let output = process(&reader, &reader, msg.clone());
 
         if let Err(err) = output {
-            assert_eq!(err.kind(), &Kind::ClientAlreadyExists(msg.client_id));
+            assert!(matches!(
+                err.detail(),
+                ErrorDetail::ClientAlreadyExists(e) if e.client_id == msg.client_id
+            ));
         } else {
             panic!("expected an error");
         }
@@ -159,6 +190,7 @@ mod tests {
             client_type: None,
             client_state: Some(MockClientState(0)),
             consensus_state: None,
+            historical_info: None,
         };
 
         let msg = MsgCreateAnyClient {
@@ -168,10 +200,13 @@ mod tests {
             consensus_state: MockConsensusState(42).into(),
         };
 
-        let output = process(&reader, msg.clone());
+        let output = process(&reader, &reader, msg.clone());
 
         if let Err(err) = output {
-            assert_eq!(err.kind(), &Kind::ClientAlreadyExists(msg.client_id));
+            assert!(matches!(
+                err.detail(),
+                ErrorDetail::ClientAlreadyExists(e) if e.client_id == msg.client_id
+            ));
         } else {
             panic!("expected an error");
         }
@@ -187,6 +222,7 @@ mod tests {
             client_type: None,
             client_state: None,
             consensus_state: None,
+            historical_info: None,
         };
 
         let ics_msg = MsgCreateClient {
@@ -206,7 +242,7 @@ mod tests {
             consensus_state: ics_msg.consensus_state(),
         };
 
-        let output = process(&reader, msg.clone());
+        let output = process(&reader, &reader, msg.clone());
 
         match output {
             Ok(HandlerOutput {
@@ -232,4 +268,79 @@ mod tests {
             }
         }
     }
-}
\ No newline at end of file
+
+    #[test]
+    fn test_create_client_host_chain_consistent_consensus_state() {
+        let client_id: ClientId = "mockclient".parse().unwrap();
+
+        let reader = MockClientReader {
+            client_id: client_id.clone(),
+            client_type: None,
+            client_state: None,
+            consensus_state: None,
+            historical_info: Some(HistoricalInfo {
+                header: MockHeader(42),
+            }),
+        };
+
+        let msg = MsgCreateAnyClient {
+            client_id: client_id.clone(),
+            client_type: ClientType::Mock,
+            client_state: MockClientState(42).into(),
+            consensus_state: MockConsensusState(42).into(),
+        };
+
+        let output = process(&reader, &reader, msg.clone());
+
+        match output {
+            Ok(HandlerOutput { result, log, .. }) => {
+                assert_eq!(result.client_id, client_id);
+                assert_eq!(
+                    log,
+                    vec![
+                        "success: no client state found".to_string(),
+                        "success: no client type found".to_string(),
+                        "success: submitted consensus state matches host chain history"
+                            .to_string(),
+                    ]
+                );
+            }
+            Err(err) => {
+                panic!("unexpected error: {}", err);
+            }
+        }
+    }
+
+    #[test]
+    fn test_create_client_host_chain_inconsistent_consensus_state() {
+        let client_id: ClientId = "mockclient".parse().unwrap();
+
+        let reader = MockClientReader {
+            client_id: client_id.clone(),
+            client_type: None,
+            client_state: None,
+            consensus_state: None,
+            historical_info: Some(HistoricalInfo {
+                header: MockHeader(42),
+            }),
+        };
+
+        let msg = MsgCreateAnyClient {
+            client_id: client_id.clone(),
+            client_type: ClientType::Mock,
+            client_state: MockClientState(42).into(),
+            consensus_state: MockConsensusState(24).into(),
+        };
+
+        let output = process(&reader, &reader, msg);
+
+        if let Err(err) = output {
+            assert!(matches!(
+                err.detail(),
+                ErrorDetail::InvalidConsensusState(e) if e.client_id == client_id
+            ));
+        } else {
+            panic!("expected an error");
+        }
+    }
+}
@@ -0,0 +1,171 @@
+#![allow(unreachable_code, unused_variables)]
+
+use crate::handler::{HandlerOutput, HandlerResult};
+use crate::ics02_client::client_def::{AnyClient, ClientDef};
+use crate::ics02_client::error::Error;
+use crate::ics02_client::handler::{ClientEvent, ClientKeeper, ClientReader};
+use crate::ics02_client::msgs::MsgSubmitAnyMisbehaviour;
+use crate::ics02_client::state::ClientState;
+use crate::ics24_host::identifier::ClientId;
+
+#[derive(Debug)]
+pub struct MisbehaviourResult<CD: ClientDef> {
+    client_id: ClientId,
+    client_state: CD::ClientState,
+}
+
+/// Handles a `MsgSubmitAnyMisbehaviour`, verifying that the two submitted headers are
+/// conflicting evidence against the same client and, if so, freezing the client so that
+/// no further updates can be applied against it.
+pub fn process(
+    ctx: &dyn ClientReader,
+    msg: MsgSubmitAnyMisbehaviour<AnyClient>,
+) -> HandlerResult<MisbehaviourResult<AnyClient>, Error> {
+    let mut output = HandlerOutput::builder();
+
+    let MsgSubmitAnyMisbehaviour {
+        client_id,
+        header1,
+        header2,
+    } = msg;
+
+    let client_type = ctx
+        .client_type(&client_id)
+        .ok_or_else(|| Error::client_not_found(client_id.clone()))?;
+
+    let client_state = ctx
+        .client_state(&client_id)
+        .ok_or_else(|| Error::client_not_found(client_id.clone()))?;
+
+    if client_state.frozen_height().is_some() {
+        return Err(Error::frozen_client(client_id));
+    }
+
+    output.log("success: retrieved client type and client state");
+
+    let client_def = AnyClient::from(client_type);
+
+    let new_client_state = client_def
+        .check_misbehaviour_and_update_state(client_state, header1, header2)
+        .map_err(|e| Error::misbehaviour_verification_failure(e.to_string()))?;
+
+    output.emit(ClientEvent::ClientMisbehaviour(client_id.clone()));
+
+    Ok(output.with_result(MisbehaviourResult {
+        client_id,
+        client_state: new_client_state,
+    }))
+}
+
+pub fn keep(
+    keeper: &mut dyn ClientKeeper,
+    result: MisbehaviourResult<AnyClient>,
+) -> Result<(), Error> {
+    keeper.store_client_state(result.client_id, result.client_state)?;
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::ics02_client::client_type::ClientType;
+    use crate::ics02_client::error::ErrorDetail;
+    use crate::ics02_client::mocks::*;
+
+    #[test]
+    fn test_misbehaviour_ok() {
+        let client_id: ClientId = "mockclient".parse().unwrap();
+
+        let reader = MockClientReader {
+            client_id: client_id.clone(),
+            client_type: Some(ClientType::Mock),
+            client_state: Some(MockClientState(42)),
+            consensus_state: Some(MockConsensusState(42)),
+            historical_info: None,
+        };
+
+        // Two distinct headers (at heights 46 and 47) for the same client: conflicting
+        // evidence, as opposed to two identical headers.
+        let msg = MsgSubmitAnyMisbehaviour {
+            client_id: client_id.clone(),
+            header1: MockHeader(46).into(),
+            header2: MockHeader(47).into(),
+        };
+
+        let output = process(&reader, msg);
+
+        match output {
+            Ok(HandlerOutput { result, events, .. }) => {
+                assert_eq!(result.client_id, client_id);
+                assert_eq!(
+                    events,
+                    vec![ClientEvent::ClientMisbehaviour(client_id).into()]
+                );
+            }
+            Err(err) => {
+                panic!("unexpected error: {}", err);
+            }
+        }
+    }
+
+    #[test]
+    fn test_misbehaviour_already_frozen_client() {
+        let client_id: ClientId = "mockclient".parse().unwrap();
+
+        let reader = MockClientReader {
+            client_id: client_id.clone(),
+            client_type: Some(ClientType::Mock),
+            client_state: Some(MockClientState(42).frozen()),
+            consensus_state: Some(MockConsensusState(42)),
+            historical_info: None,
+        };
+
+        let msg = MsgSubmitAnyMisbehaviour {
+            client_id: client_id.clone(),
+            header1: MockHeader(46).into(),
+            header2: MockHeader(47).into(),
+        };
+
+        let output = process(&reader, msg);
+
+        if let Err(err) = output {
+            assert!(matches!(
+                err.detail(),
+                ErrorDetail::FrozenClient(e) if e.client_id == client_id
+            ));
+        } else {
+            panic!("expected an error");
+        }
+    }
+
+    #[test]
+    fn test_misbehaviour_non_existing_client() {
+        let client_id: ClientId = "mockclient".parse().unwrap();
+
+        let reader = MockClientReader {
+            client_id: client_id.clone(),
+            client_type: None,
+            client_state: None,
+            consensus_state: None,
+            historical_info: None,
+        };
+
+        let msg = MsgSubmitAnyMisbehaviour {
+            client_id: client_id.clone(),
+            header1: MockHeader(46).into(),
+            header2: MockHeader(46).into(),
+        };
+
+        let output = process(&reader, msg);
+
+        if let Err(err) = output {
+            assert!(matches!(
+                err.detail(),
+                ErrorDetail::ClientNotFound(e) if e.client_id == client_id
+            ));
+        } else {
+            panic!("expected an error");
+        }
+    }
+}
@@ -0,0 +1,146 @@
+#![allow(unreachable_code, unused_variables)]
+
+use crate::handler::{HandlerOutput, HandlerResult};
+use crate::ics02_client::client_def::{AnyClient, ClientDef};
+use crate::ics02_client::error::Error;
+use crate::ics02_client::handler::{ClientEvent, ClientKeeper, ClientReader};
+use crate::ics02_client::msgs::MsgUpdateAnyClient;
+use crate::ics02_client::state::{ClientState, ConsensusState};
+use crate::ics24_host::identifier::ClientId;
+
+#[derive(Debug)]
+pub struct UpdateClientResult<CD: ClientDef> {
+    client_id: ClientId,
+    client_state: CD::ClientState,
+    consensus_state: CD::ConsensusState,
+}
+
+pub fn process(
+    ctx: &dyn ClientReader,
+    msg: MsgUpdateAnyClient<AnyClient>,
+) -> HandlerResult<UpdateClientResult<AnyClient>, Error> {
+    let mut output = HandlerOutput::builder();
+
+    let MsgUpdateAnyClient { client_id, header } = msg;
+
+    let client_type = ctx
+        .client_type(&client_id)
+        .ok_or_else(|| Error::client_not_found(client_id.clone()))?;
+
+    let client_state = ctx
+        .client_state(&client_id)
+        .ok_or_else(|| Error::client_not_found(client_id.clone()))?;
+
+    if client_state.frozen_height().is_some() {
+        return Err(Error::frozen_client(client_id));
+    }
+
+    let consensus_state = ctx
+        .consensus_state(&client_id, client_state.latest_height())
+        .ok_or_else(|| {
+            Error::consensus_state_not_found(client_id.clone(), client_state.latest_height())
+        })?;
+
+    output.log("success: retrieved client type, client state and consensus state");
+
+    let client_def = AnyClient::from(client_type);
+
+    let (new_client_state, new_consensus_state) = client_def
+        .check_header_and_update_state(client_state, consensus_state, header)
+        .map_err(|e| Error::header_verification_failure(e.to_string()))?;
+
+    output.emit(ClientEvent::ClientUpdated(
+        client_id.clone(),
+        new_client_state.latest_height(),
+    ));
+
+    Ok(output.with_result(UpdateClientResult {
+        client_id,
+        client_state: new_client_state,
+        consensus_state: new_consensus_state,
+    }))
+}
+
+pub fn keep(
+    keeper: &mut dyn ClientKeeper,
+    result: UpdateClientResult<AnyClient>,
+) -> Result<(), Error> {
+    keeper.store_client_state(result.client_id.clone(), result.client_state)?;
+    // `store_consensus_state` keys the consensus state by its own height, which
+    // `check_header_and_update_state` derives from the header being processed — so this
+    // keeps historical heights queryable for proof verification, the same as `create_client`.
+    keeper.store_consensus_state(result.client_id, result.consensus_state)?;
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::ics02_client::client_type::ClientType;
+    use crate::ics02_client::error::ErrorDetail;
+    use crate::ics02_client::mocks::*;
+    use crate::Height;
+
+    #[test]
+    fn test_update_client_ok() {
+        let client_id: ClientId = "mockclient".parse().unwrap();
+
+        let reader = MockClientReader {
+            client_id: client_id.clone(),
+            client_type: Some(ClientType::Mock),
+            client_state: Some(MockClientState(42)),
+            consensus_state: Some(MockConsensusState(42)),
+            historical_info: None,
+        };
+
+        let msg = MsgUpdateAnyClient {
+            client_id,
+            header: MockHeader(46).into(),
+        };
+
+        let output = process(&reader, msg.clone());
+
+        match output {
+            Ok(HandlerOutput { result, events, .. }) => {
+                assert_eq!(result.client_id, msg.client_id);
+                assert_eq!(
+                    events,
+                    vec![ClientEvent::ClientUpdated(msg.client_id, Height::new(0, 46)).into()]
+                );
+            }
+            Err(err) => {
+                panic!("unexpected error: {}", err);
+            }
+        }
+    }
+
+    #[test]
+    fn test_update_non_existing_client() {
+        let client_id: ClientId = "mockclient".parse().unwrap();
+
+        let reader = MockClientReader {
+            client_id: client_id.clone(),
+            client_type: None,
+            client_state: None,
+            consensus_state: None,
+            historical_info: None,
+        };
+
+        let msg = MsgUpdateAnyClient {
+            client_id: client_id.clone(),
+            header: MockHeader(46).into(),
+        };
+
+        let output = process(&reader, msg);
+
+        if let Err(err) = output {
+            assert!(matches!(
+                err.detail(),
+                ErrorDetail::ClientNotFound(e) if e.client_id == client_id
+            ));
+        } else {
+            panic!("expected an error");
+        }
+    }
+}
@@ -0,0 +1,104 @@
+//! Builders that assemble ICS02 client messages from live chain state, so a relayer can
+//! bootstrap a client without hand-building `ClientState`/`ConsensusState` values itself.
+
+use std::time::Duration;
+
+use tendermint::account::Id as AccountId;
+
+use ibc::ics02_client::client_def::AnyClient;
+use ibc::ics02_client::msgs::MsgCreateAnyClient;
+use ibc::ics07_tendermint::header::Header as TendermintHeader;
+use ibc::ics07_tendermint::msgs::create_client::MsgCreateClient;
+use ibc::ics24_host::identifier::ClientId;
+use ibc::Height;
+
+/// A handle onto a source chain, exposing just enough to bootstrap a client that will track
+/// it: its latest signed header, and the header at any earlier height a client might start
+/// trusting from.
+pub trait ChainHandle {
+    fn latest_height(&self) -> Height;
+    fn header_at(&self, height: Height) -> TendermintHeader;
+}
+
+/// Parameters controlling how conservative the freshly built client is. These mirror the
+/// fields `MsgCreateClient` already carries; they're surfaced here so a relayer can tune them
+/// per counterparty instead of hard-coding them alongside the query logic.
+#[derive(Clone, Debug)]
+pub struct ClientSettings {
+    pub trusting_period: Duration,
+    pub unbonding_period: Duration,
+    pub max_clock_drift: Duration,
+}
+
+/// Queries `chain` for its latest signed header and assembles a ready-to-submit
+/// `MsgCreateAnyClient` tracking it, starting the client's trusted state from that header.
+pub fn build_create_client<Chain: ChainHandle>(
+    chain: &Chain,
+    client_id: ClientId,
+    settings: ClientSettings,
+    signer: AccountId,
+) -> MsgCreateAnyClient<AnyClient> {
+    let header = chain.header_at(chain.latest_height());
+
+    let ics_msg = MsgCreateClient {
+        client_id,
+        header,
+        trusting_period: settings.trusting_period,
+        unbonding_period: settings.unbonding_period,
+        max_clock_drift: settings.max_clock_drift,
+        signer,
+    };
+
+    MsgCreateAnyClient {
+        client_id: ics_msg.client_id().clone(),
+        client_type: ics_msg.client_type(),
+        client_state: ics_msg.client_state(),
+        consensus_state: ics_msg.consensus_state(),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use ibc::ics02_client::client_type::ClientType;
+    use ibc::ics07_tendermint::header::test_util::get_dummy_header;
+    use std::str::FromStr;
+
+    struct MockSourceChain {
+        header: TendermintHeader,
+    }
+
+    impl ChainHandle for MockSourceChain {
+        fn latest_height(&self) -> Height {
+            self.header.signed_header.header.height.into()
+        }
+
+        fn header_at(&self, _height: Height) -> TendermintHeader {
+            self.header.clone()
+        }
+    }
+
+    #[test]
+    fn test_build_create_client() {
+        let chain = MockSourceChain {
+            header: get_dummy_header(),
+        };
+
+        let client_id: ClientId = "tendermint".parse().unwrap();
+        let signer = AccountId::from_str("7C2BB42A8BE69791EC763E51F5A49BCD41E82237").unwrap();
+
+        let msg = build_create_client(
+            &chain,
+            client_id.clone(),
+            ClientSettings {
+                trusting_period: Duration::from_secs(64000),
+                unbonding_period: Duration::from_secs(128000),
+                max_clock_drift: Duration::from_millis(3000),
+            },
+            signer,
+        );
+
+        assert_eq!(msg.client_id, client_id);
+        assert_eq!(msg.client_type, ClientType::Tendermint);
+    }
+}